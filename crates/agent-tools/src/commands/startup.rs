@@ -4,8 +4,9 @@ use std::process::Command;
 use std::time::SystemTime;
 
 use crate::commands::sync;
+use crate::commands::update::release;
 use crate::commands::vcs::{self, Vcs};
-use crate::config::Config;
+use crate::config::{Config, UpdateChannel};
 use crate::paths;
 
 pub fn run() -> anyhow::Result<()> {
@@ -21,7 +22,7 @@ pub fn run() -> anyhow::Result<()> {
 
     // Phase 1: Auto-update check
     if config.auto_update_on_startup {
-        if let Err(e) = check_and_update(&agent_tools_home) {
+        if let Err(e) = check_and_update(&agent_tools_home, config.update_channel) {
             eprintln!("startup: auto-update check failed: {e}");
         }
     }
@@ -34,35 +35,29 @@ pub fn run() -> anyhow::Result<()> {
     Ok(())
 }
 
-fn check_and_update(agent_tools_home: &std::path::Path) -> anyhow::Result<()> {
-    let vcs = match vcs::detect_vcs(agent_tools_home) {
-        Some(v) => v,
-        None => return Ok(()),
-    };
-
-    // Fetch remote
-    vcs::fetch_remote(agent_tools_home, vcs)?;
-
-    // Check for updates
-    if !vcs::has_remote_updates(agent_tools_home, vcs)? {
-        return Ok(());
-    }
-
-    // Check if tree is clean
-    match vcs {
-        Vcs::Jj => {
-            if let Err(e) = vcs::check_jj_clean(agent_tools_home) {
-                eprintln!("startup: {e}, skipping auto-update");
+fn check_and_update(
+    agent_tools_home: &std::path::Path,
+    channel: UpdateChannel,
+) -> anyhow::Result<()> {
+    let update_args: &[&str] = match channel {
+        UpdateChannel::Source => {
+            if !has_source_updates(agent_tools_home)? {
                 return Ok(());
             }
+            &["update", "--channel", "source"]
         }
-        Vcs::Git => {
-            if let Err(e) = vcs::check_git_clean(agent_tools_home) {
-                eprintln!("startup: {e}, skipping auto-update");
+        UpdateChannel::Release => {
+            let release = release::latest_release()?;
+            // Wait until the binary for this platform is attached to the release
+            if !release.is_newer_than(env!("CARGO_PKG_VERSION"))
+                || !release.has_platform_assets()?
+            {
                 return Ok(());
             }
+            // Without --channel so update_source_fallback in config.yaml still applies
+            &["update"]
         }
-    }
+    };
 
     // Acquire lock to prevent concurrent background updates
     let lock_path = agent_tools_home.join("logs").join("startup-update.lock");
@@ -104,7 +99,7 @@ fn check_and_update(agent_tools_home: &std::path::Path) -> anyhow::Result<()> {
 
     let exe = std::env::current_exe().context("Failed to get current executable path")?;
     let spawn_result = Command::new(&exe)
-        .arg("update")
+        .args(update_args)
         .stdout(
             fs::OpenOptions::new()
                 .create(true)
@@ -126,3 +121,31 @@ fn check_and_update(agent_tools_home: &std::path::Path) -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Whether the VCS checkout has remote updates that can be pulled cleanly
+fn has_source_updates(agent_tools_home: &std::path::Path) -> anyhow::Result<bool> {
+    let vcs = match vcs::detect_vcs(agent_tools_home) {
+        Some(v) => v,
+        None => return Ok(false),
+    };
+
+    // Fetch remote
+    vcs::fetch_remote(agent_tools_home, vcs)?;
+
+    // Check for updates
+    if !vcs::has_remote_updates(agent_tools_home, vcs)? {
+        return Ok(false);
+    }
+
+    // Check if tree is clean
+    let clean = match vcs {
+        Vcs::Jj => vcs::check_jj_clean(agent_tools_home),
+        Vcs::Git => vcs::check_git_clean(agent_tools_home),
+    };
+    if let Err(e) = clean {
+        eprintln!("startup: {e}, skipping auto-update");
+        return Ok(false);
+    }
+
+    Ok(true)
+}
@@ -5,9 +5,13 @@ use std::process::Command;
 
 use crate::commands::build;
 use crate::commands::vcs::{Vcs, check_git_clean, check_jj_clean, detect_vcs};
+use crate::config::{Config, UpdateChannel};
 use crate::paths;
 
-pub fn run() -> Result<()> {
+pub mod release;
+
+/// Update agent-tools through `channel` (default: `update_channel` in config.yaml)
+pub fn run(channel: Option<UpdateChannel>) -> Result<()> {
     let agent_tools_home = paths::agent_tools_home()?;
 
     if !agent_tools_home.exists() {
@@ -17,7 +21,49 @@ pub fn run() -> Result<()> {
         );
     }
 
-    let vcs = detect_vcs(&agent_tools_home).ok_or_else(|| {
+    // config.yaml is only read without --channel, and a broken config falls back to
+    // defaults: updating may be what brings a binary that can parse it
+    let (channel, source_fallback) = match channel {
+        Some(channel) => (channel, false),
+        None => {
+            let config = Config::load(&paths::config_path()?).unwrap_or_else(|e| {
+                eprintln!("update: failed to load config: {e}, using defaults");
+                Config::default()
+            });
+            (config.update_channel, config.update_source_fallback)
+        }
+    };
+
+    match channel {
+        UpdateChannel::Source => run_source_update(&agent_tools_home),
+        UpdateChannel::Release => {
+            println!("{}", "Updating agent-tools from release...".green().bold());
+            println!();
+
+            match release::install_latest(&agent_tools_home) {
+                Ok(true) => {
+                    println!();
+                    println!("{}", "Update complete!".green().bold());
+                }
+                Ok(false) => {}
+                Err(e) => {
+                    if !source_fallback || e.is::<release::ChecksumMismatch>() {
+                        return Err(e);
+                    }
+                    println!("{} Release update failed: {}", "!".yellow(), e);
+                    println!("  Falling back to source build (update_source_fallback: true)");
+                    println!();
+                    return run_source_update(&agent_tools_home);
+                }
+            }
+
+            Ok(())
+        }
+    }
+}
+
+fn run_source_update(agent_tools_home: &std::path::Path) -> Result<()> {
+    let vcs = detect_vcs(agent_tools_home).ok_or_else(|| {
         anyhow::anyhow!(
             "No VCS detected in {}\nExpected .jj or .git directory.",
             agent_tools_home.display()
@@ -28,8 +74,8 @@ pub fn run() -> Result<()> {
     println!();
 
     match vcs {
-        Vcs::Jj => run_jj_update(&agent_tools_home)?,
-        Vcs::Git => run_git_update(&agent_tools_home)?,
+        Vcs::Jj => run_jj_update(agent_tools_home)?,
+        Vcs::Git => run_git_update(agent_tools_home)?,
     }
 
     // Build and install using shared function
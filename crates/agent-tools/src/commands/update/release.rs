@@ -0,0 +1,322 @@
+use anyhow::{Context, Result, bail};
+use colored::Colorize;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+#[cfg(unix)]
+use std::os::unix::fs::PermissionsExt;
+
+/// GitHub API endpoint describing the latest release (tag and asset URLs)
+const DEFAULT_RELEASE_URL: &str = "https://api.github.com/repos/wthrk/agent-tools/releases/latest";
+
+/// curl --max-time for the release metadata request (runs on every startup)
+const METADATA_TIMEOUT_SECS: u32 = 10;
+
+/// curl --max-time for downloading release assets
+const ASSET_TIMEOUT_SECS: u32 = 300;
+
+/// Checksum manifest published alongside the release binaries (sha256sum format)
+const CHECKSUMS_ASSET: &str = "SHA256SUMS";
+
+/// Get the URL of the latest release metadata
+/// Can be overridden with AGENT_TOOLS_RELEASE_URL environment variable
+fn release_url() -> String {
+    std::env::var("AGENT_TOOLS_RELEASE_URL")
+        .ok()
+        .filter(|url| !url.trim().is_empty())
+        .map_or_else(
+            || DEFAULT_RELEASE_URL.to_string(),
+            |url| url.trim_end_matches('/').to_string(),
+        )
+}
+
+/// Downloaded binary does not match SHA256SUMS
+///
+/// Never recovered from by falling back to a source build: the download may have been tampered with.
+#[derive(Debug, thiserror::Error)]
+#[error("Checksum mismatch for {asset}\n  expected: {expected}\n  actual:   {actual}")]
+pub struct ChecksumMismatch {
+    asset: String,
+    expected: String,
+    actual: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ReleaseAsset {
+    name: String,
+    browser_download_url: String,
+}
+
+/// Latest release as returned by the GitHub releases API
+#[derive(Debug, Deserialize)]
+pub struct Release {
+    pub tag_name: String,
+    #[serde(default)]
+    assets: Vec<ReleaseAsset>,
+}
+
+impl Release {
+    /// Version without the leading `v` of the tag (e.g. `v1.2.0` -> `1.2.0`)
+    pub fn version(&self) -> &str {
+        self.tag_name.trim_start_matches('v')
+    }
+
+    /// Whether this release is newer than `current` under SemVer ordering
+    /// (pre-releases sort below their release). Unparseable versions are never newer.
+    pub fn is_newer_than(&self, current: &str) -> bool {
+        match (
+            semver::Version::parse(self.version()),
+            semver::Version::parse(current),
+        ) {
+            (Ok(latest), Ok(current)) => latest > current,
+            (Err(e), _) | (_, Err(e)) => {
+                eprintln!(
+                    "{} Cannot compare release {} with {}: {}, skipping update",
+                    "!".yellow(),
+                    self.tag_name,
+                    current,
+                    e
+                );
+                false
+            }
+        }
+    }
+
+    /// Whether the binary for this platform and SHA256SUMS are attached.
+    ///
+    /// The release is published before its binaries are uploaded, and a failed
+    /// build leg leaves its platform out, so a newer tag is not always installable.
+    pub fn has_platform_assets(&self) -> Result<bool> {
+        let asset = platform_asset_name()?;
+        Ok(self.asset_url(&asset).is_ok() && self.asset_url(CHECKSUMS_ASSET).is_ok())
+    }
+
+    fn asset_url(&self, name: &str) -> Result<&str> {
+        self.assets
+            .iter()
+            .find(|asset| asset.name == name)
+            .map(|asset| asset.browser_download_url.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Release {} has no asset {}", self.tag_name, name))
+    }
+}
+
+/// Release asset name for the given platform (e.g. `agent-tools-aarch64-apple-darwin`)
+fn asset_name(arch: &str, os: &str) -> Result<String> {
+    let target = match (arch, os) {
+        ("x86_64", "linux") => "x86_64-unknown-linux-musl",
+        ("aarch64", "linux") => "aarch64-unknown-linux-musl",
+        ("x86_64", "macos") => "x86_64-apple-darwin",
+        ("aarch64", "macos") => "aarch64-apple-darwin",
+        _ => bail!("No prebuilt agent-tools binary is published for {arch}-{os}"),
+    };
+    Ok(format!("agent-tools-{target}"))
+}
+
+/// Release asset name for the running platform
+fn platform_asset_name() -> Result<String> {
+    asset_name(std::env::consts::ARCH, std::env::consts::OS)
+}
+
+/// Fetch the latest release metadata
+pub fn latest_release() -> Result<Release> {
+    ensure_curl_exists()?;
+
+    let url = release_url();
+    let body = download(&url, METADATA_TIMEOUT_SECS)?;
+    serde_json::from_slice(&body)
+        .with_context(|| format!("Failed to parse release metadata: {url}"))
+}
+
+/// Install the latest release binary if it is newer than the running one.
+///
+/// The binary is verified against SHA256SUMS, the current binary is backed up,
+/// and bin/agent-tools is replaced atomically. Returns whether a new binary was installed.
+pub fn install_latest(agent_tools_home: &Path) -> Result<bool> {
+    println!("{} Checking latest release...", "→".blue());
+    let release = latest_release()?;
+    let current_version = env!("CARGO_PKG_VERSION");
+    if !release.is_newer_than(current_version) {
+        println!(
+            "  {} Already up to date ({}, latest release {})",
+            "✓".green(),
+            current_version,
+            release.tag_name
+        );
+        return Ok(false);
+    }
+
+    let asset = platform_asset_name()?;
+    if !release.has_platform_assets()? {
+        println!(
+            "  {} Release {} has no {} or {} yet, skipping (upload may still be in progress)",
+            "!".yellow(),
+            release.tag_name,
+            asset,
+            CHECKSUMS_ASSET
+        );
+        return Ok(false);
+    }
+    println!(
+        "  {} New release available: {} → {}",
+        "✓".green(),
+        current_version,
+        release.version()
+    );
+
+    let bin_dir = agent_tools_home.join("bin");
+    let current_bin = bin_dir.join("agent-tools");
+    // Stage inside bin/ so the final rename stays on the same filesystem
+    let staged_bin = bin_dir.join(".agent-tools.download");
+
+    println!("{} Downloading {}...", "→".blue(), asset);
+    let checksums = download(release.asset_url(CHECKSUMS_ASSET)?, ASSET_TIMEOUT_SECS)?;
+    let checksums = String::from_utf8_lossy(&checksums);
+    let expected = find_checksum(&checksums, &asset)
+        .ok_or_else(|| anyhow::anyhow!("No checksum for {} in {}", asset, CHECKSUMS_ASSET))?;
+    let binary = download(release.asset_url(&asset)?, ASSET_TIMEOUT_SECS)?;
+    println!("  {} Downloaded {} bytes", "✓".green(), binary.len());
+
+    println!("{} Verifying checksum...", "→".blue());
+    let actual = sha256_hex(&binary);
+    if !actual.eq_ignore_ascii_case(expected) {
+        return Err(ChecksumMismatch {
+            asset,
+            expected: expected.to_string(),
+            actual,
+        }
+        .into());
+    }
+    println!("  {} Checksum verified", "✓".green());
+
+    fs::create_dir_all(&bin_dir)?;
+    fs::write(&staged_bin, &binary)
+        .with_context(|| format!("Failed to write {}", staged_bin.display()))?;
+    #[cfg(unix)]
+    fs::set_permissions(&staged_bin, fs::Permissions::from_mode(0o755))
+        .with_context(|| format!("Failed to set permissions on {}", staged_bin.display()))?;
+
+    if let Err(e) = super::backup_binary(agent_tools_home) {
+        let _ = fs::remove_file(&staged_bin);
+        return Err(e);
+    }
+    if let Err(e) = fs::rename(&staged_bin, &current_bin) {
+        let _ = fs::remove_file(&staged_bin);
+        return Err(e).context("Failed to replace bin/agent-tools");
+    }
+    println!(
+        "  {} Installed new binary to {}",
+        "✓".green(),
+        current_bin.display()
+    );
+
+    Ok(true)
+}
+
+fn ensure_curl_exists() -> Result<()> {
+    let output = Command::new("curl")
+        .arg("--version")
+        .output()
+        .context("Failed to execute curl --version")?;
+    if output.status.success() {
+        return Ok(());
+    }
+    bail!("curl is required for release updates but is not available.");
+}
+
+fn download(url: &str, max_time_secs: u32) -> Result<Vec<u8>> {
+    let output = Command::new("curl")
+        .args(["-fsSL", "--max-time", &max_time_secs.to_string(), url])
+        .output()
+        .with_context(|| format!("Failed to execute curl for {url}"))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        bail!("Download failed: {}\n{}", url, stderr.trim());
+    }
+
+    Ok(output.stdout)
+}
+
+/// Find the checksum for `asset` in sha256sum output (`<hex>  <name>` or `<hex> *<name>`)
+fn find_checksum<'a>(checksums: &'a str, asset: &str) -> Option<&'a str> {
+    checksums.lines().find_map(|line| {
+        let (hash, name) = line.trim().split_once(char::is_whitespace)?;
+        let name = name.trim_start();
+        let name = name.strip_prefix('*').unwrap_or(name);
+        (name == asset).then_some(hash)
+    })
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    Sha256::digest(bytes)
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Release, asset_name, find_checksum, sha256_hex};
+
+    fn release(tag: &str) -> Release {
+        Release {
+            tag_name: tag.to_string(),
+            assets: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn release_is_newer_than_uses_semver_ordering() {
+        assert!(release("v1.10.0").is_newer_than("1.9.3"));
+        assert!(!release("v1.2.0").is_newer_than("1.2.0"));
+        assert!(!release("v1.1.0").is_newer_than("1.2.0"));
+        assert!(!release("v1.2.0-rc1").is_newer_than("1.2.0"));
+        assert!(release("v1.2.0").is_newer_than("1.2.0-rc1"));
+        assert!(!release("v1.2.0-rc1").is_newer_than("1.3.0"));
+    }
+
+    #[test]
+    fn release_is_newer_than_rejects_unparseable_versions() {
+        assert!(!release("v1.2").is_newer_than("1.1.0"));
+        assert!(!release("nightly").is_newer_than("1.1.0"));
+    }
+
+    #[test]
+    fn asset_name_maps_supported_platforms() {
+        assert_eq!(
+            asset_name("aarch64", "macos").unwrap(),
+            "agent-tools-aarch64-apple-darwin"
+        );
+        assert_eq!(
+            asset_name("x86_64", "linux").unwrap(),
+            "agent-tools-x86_64-unknown-linux-musl"
+        );
+        assert!(asset_name("x86_64", "windows").is_err());
+    }
+
+    #[test]
+    fn find_checksum_supports_text_and_binary_mode() {
+        let sums = "aaa  agent-tools-x86_64-unknown-linux-musl\n\
+                    bbb *agent-tools-aarch64-apple-darwin\n";
+        assert_eq!(
+            find_checksum(sums, "agent-tools-x86_64-unknown-linux-musl"),
+            Some("aaa")
+        );
+        assert_eq!(
+            find_checksum(sums, "agent-tools-aarch64-apple-darwin"),
+            Some("bbb")
+        );
+        assert_eq!(find_checksum(sums, "agent-tools-x86_64-apple-darwin"), None);
+    }
+
+    #[test]
+    fn sha256_hex_matches_known_digest() {
+        assert_eq!(
+            sha256_hex(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+}
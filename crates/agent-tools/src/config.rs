@@ -20,6 +20,17 @@ fn default_mcp_type() -> String {
     "stdio".to_string()
 }
 
+/// How `agent-tools update` obtains a new binary
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum UpdateChannel {
+    /// Pull the local checkout and rebuild with cargo
+    #[default]
+    Source,
+    /// Download a prebuilt binary from GitHub Releases
+    Release,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
     #[serde(default = "default_config_version")]
@@ -56,6 +67,14 @@ pub struct Config {
     /// Auto-update agent-tools on startup (fetch + rebuild if updates available)
     #[serde(default = "default_true")]
     pub auto_update_on_startup: bool,
+
+    /// Update channel for `agent-tools update` (source or release)
+    #[serde(default)]
+    pub update_channel: UpdateChannel,
+
+    /// Fall back to a source build when the release update fails
+    #[serde(default)]
+    pub update_source_fallback: bool,
 }
 
 fn default_config_version() -> u32 {
@@ -78,6 +97,8 @@ impl Default for Config {
             manage_codex_config: false,
             claude_mcp_servers: HashMap::new(),
             auto_update_on_startup: true,
+            update_channel: UpdateChannel::Source,
+            update_source_fallback: false,
         }
     }
 }
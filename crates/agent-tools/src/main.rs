@@ -28,8 +28,13 @@ enum Commands {
     /// Initialize agent-tools (create directories, setup PATH)
     Init,
 
-    /// Update agent-tools (git pull && cargo build)
-    Update,
+    /// Update agent-tools (git pull && cargo build, or prebuilt release binary)
+    Update {
+        /// Update channel (default: update_channel in config.yaml).
+        /// An explicit channel skips config.yaml, including update_source_fallback
+        #[arg(long, value_enum)]
+        channel: Option<config::UpdateChannel>,
+    },
 
     /// Rebase current work onto latest main
     Rebase,
@@ -239,7 +244,7 @@ fn main() -> anyhow::Result<()> {
     match cli.command {
         Commands::Build => commands::build::run(),
         Commands::Init => commands::init::run(),
-        Commands::Update => commands::update::run(),
+        Commands::Update { channel } => commands::update::run(channel),
         Commands::Rebase => commands::rebase::run(),
         Commands::Status => commands::status::run(),
         Commands::Sync { dry_run, prune } => commands::sync::run(dry_run, prune),
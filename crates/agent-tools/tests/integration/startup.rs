@@ -0,0 +1,80 @@
+//! Startup command tests
+
+use super::common::TestEnv;
+use std::fs;
+
+#[test]
+fn test_startup_release_channel_skips_update_when_current() {
+    let env = TestEnv::new();
+    env.create_config("config_version: 1\nupdate_channel: release\n");
+
+    // Latest release matches the running version
+    let metadata = env.home.path().join("latest.json");
+    fs::write(
+        &metadata,
+        format!(
+            r#"{{"tag_name": "v{}", "assets": []}}"#,
+            env!("CARGO_PKG_VERSION")
+        ),
+    )
+    .unwrap();
+
+    env.cmd()
+        .args(["startup"])
+        .env(
+            "AGENT_TOOLS_RELEASE_URL",
+            format!("file://{}", metadata.display()),
+        )
+        .assert()
+        .success();
+
+    // No background update was launched
+    assert!(
+        !env.agent_tools_home
+            .join("logs/startup-update.lock")
+            .exists()
+    );
+}
+
+#[test]
+fn test_startup_release_channel_does_not_require_vcs() {
+    let env = TestEnv::new();
+    env.create_config("config_version: 1\nupdate_channel: release\n");
+
+    // Without a VCS checkout the release check still runs (and reports its failure)
+    env.cmd()
+        .args(["startup"])
+        .env(
+            "AGENT_TOOLS_RELEASE_URL",
+            format!("file://{}", env.home.path().join("missing").display()),
+        )
+        .assert()
+        .success()
+        .stderr(predicates::str::contains("auto-update check failed"));
+}
+
+#[test]
+fn test_startup_release_channel_waits_for_platform_binary() {
+    let env = TestEnv::new();
+    env.create_config("config_version: 1\nupdate_channel: release\n");
+
+    // Newer release published before any binaries were uploaded
+    let metadata = env.home.path().join("latest.json");
+    fs::write(&metadata, r#"{"tag_name": "v999.0.0", "assets": []}"#).unwrap();
+
+    env.cmd()
+        .args(["startup"])
+        .env(
+            "AGENT_TOOLS_RELEASE_URL",
+            format!("file://{}", metadata.display()),
+        )
+        .assert()
+        .success();
+
+    // No background update was launched
+    assert!(
+        !env.agent_tools_home
+            .join("logs/startup-update.lock")
+            .exists()
+    );
+}
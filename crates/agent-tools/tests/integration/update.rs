@@ -47,3 +47,208 @@ fn test_update_with_uncommitted_changes() {
         .failure()
         .stderr(predicate::str::is_match(r"(?i)uncommitted|changes").unwrap());
 }
+
+/// Release asset name for the platform running the tests
+fn release_asset_name() -> String {
+    let target = match (std::env::consts::ARCH, std::env::consts::OS) {
+        ("aarch64", "macos") => "aarch64-apple-darwin",
+        ("x86_64", "macos") => "x86_64-apple-darwin",
+        ("aarch64", _) => "aarch64-unknown-linux-musl",
+        _ => "x86_64-unknown-linux-musl",
+    };
+    format!("agent-tools-{target}")
+}
+
+/// Publish a fake release (metadata, binary, SHA256SUMS) into a local directory.
+/// Returns the file:// URL of the release metadata for AGENT_TOOLS_RELEASE_URL.
+fn create_release(env: &TestEnv, tag: &str, binary: &[u8], checksum: &str) -> String {
+    let release_dir = env.home.path().join("release");
+    fs::create_dir_all(&release_dir).unwrap();
+    let asset = release_asset_name();
+    fs::write(release_dir.join(&asset), binary).unwrap();
+    fs::write(
+        release_dir.join("SHA256SUMS"),
+        format!("{checksum}  {asset}\n"),
+    )
+    .unwrap();
+
+    let assets = [asset.as_str(), "SHA256SUMS"];
+    write_release_metadata(&release_dir, tag, &assets)
+}
+
+/// Write release metadata listing `assets` (files in `release_dir`).
+/// Returns the file:// URL of the metadata for AGENT_TOOLS_RELEASE_URL.
+fn write_release_metadata(release_dir: &std::path::Path, tag: &str, assets: &[&str]) -> String {
+    let assets: Vec<_> = assets
+        .iter()
+        .map(|name| {
+            serde_json::json!({
+                "name": name,
+                "browser_download_url": format!("file://{}", release_dir.join(name).display()),
+            })
+        })
+        .collect();
+    let metadata = serde_json::json!({ "tag_name": tag, "assets": assets });
+    fs::write(release_dir.join("latest.json"), metadata.to_string()).unwrap();
+    format!("file://{}", release_dir.join("latest.json").display())
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    use sha2::{Digest, Sha256};
+    Sha256::digest(bytes)
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+#[test]
+fn test_update_release_channel_installs_verified_binary() {
+    let env = TestEnv::new();
+    let binary = b"#!/bin/sh\necho new\n";
+    let release_url = create_release(&env, "v999.0.0", binary, &sha256_hex(binary));
+
+    let bin_dir = env.agent_tools_home.join("bin");
+    fs::create_dir_all(&bin_dir).unwrap();
+    fs::write(bin_dir.join("agent-tools"), "old").unwrap();
+
+    // No VCS checkout is required for the release channel
+    env.cmd()
+        .args(["update", "--channel", "release"])
+        .env("AGENT_TOOLS_RELEASE_URL", &release_url)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Checksum verified"));
+
+    assert_eq!(fs::read(bin_dir.join("agent-tools")).unwrap(), binary);
+    assert!(!bin_dir.join(".agent-tools.download").exists());
+
+    // Previous binary is backed up
+    let backups: Vec<_> = fs::read_dir(env.agent_tools_home.join("backups"))
+        .unwrap()
+        .filter_map(|e| e.ok())
+        .collect();
+    assert_eq!(backups.len(), 1);
+}
+
+#[test]
+fn test_update_release_channel_rejects_checksum_mismatch() {
+    let env = TestEnv::new();
+    let release_url = create_release(&env, "v999.0.0", b"tampered", &sha256_hex(b"original"));
+    // A checksum mismatch is fatal even with source fallback enabled
+    env.create_config("config_version: 1\nupdate_channel: release\nupdate_source_fallback: true\n");
+
+    let bin_dir = env.agent_tools_home.join("bin");
+    fs::create_dir_all(&bin_dir).unwrap();
+    fs::write(bin_dir.join("agent-tools"), "old").unwrap();
+
+    env.cmd()
+        .args(["update"])
+        .env("AGENT_TOOLS_RELEASE_URL", &release_url)
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("Falling back").not())
+        .stderr(predicate::str::contains("Checksum mismatch"));
+
+    assert_eq!(
+        fs::read_to_string(bin_dir.join("agent-tools")).unwrap(),
+        "old"
+    );
+}
+
+#[test]
+fn test_update_release_channel_falls_back_to_source() {
+    let env = TestEnv::new();
+    env.create_config("config_version: 1\nupdate_channel: release\nupdate_source_fallback: true\n");
+
+    // Release download fails, then the source update fails for lack of VCS
+    env.cmd()
+        .args(["update"])
+        .env(
+            "AGENT_TOOLS_RELEASE_URL",
+            format!("file://{}", env.home.path().join("missing").display()),
+        )
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("Falling back to source build"))
+        .stderr(predicate::str::contains("No VCS detected"));
+}
+
+#[test]
+fn test_update_release_channel_already_up_to_date() {
+    let env = TestEnv::new();
+    let tag = format!("v{}", env!("CARGO_PKG_VERSION"));
+    let release_url = create_release(&env, &tag, b"same", &sha256_hex(b"same"));
+
+    let bin_dir = env.agent_tools_home.join("bin");
+    fs::create_dir_all(&bin_dir).unwrap();
+    fs::write(bin_dir.join("agent-tools"), "current").unwrap();
+
+    env.cmd()
+        .args(["update", "--channel", "release"])
+        .env("AGENT_TOOLS_RELEASE_URL", &release_url)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Already up to date"));
+
+    // Nothing is downloaded, replaced, or backed up
+    assert_eq!(
+        fs::read_to_string(bin_dir.join("agent-tools")).unwrap(),
+        "current"
+    );
+    assert!(!env.agent_tools_home.join("backups").exists());
+}
+
+#[test]
+fn test_update_ignores_broken_config() {
+    let env = TestEnv::new();
+    env.create_config("update_channel: [not, a, channel]\n");
+
+    // An explicit channel never reads config.yaml
+    env.cmd()
+        .args(["update", "--channel", "source"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("No VCS detected"))
+        .stderr(predicate::str::contains("failed to load config").not());
+
+    // Without --channel a broken config warns and uses defaults (source)
+    env.cmd()
+        .args(["update"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("failed to load config"))
+        .stderr(predicate::str::contains("No VCS detected"));
+}
+
+#[test]
+fn test_update_release_channel_skips_release_without_platform_binary() {
+    let env = TestEnv::new();
+    let binary = b"new";
+    create_release(&env, "v999.0.0", binary, &sha256_hex(binary));
+    // Release is published, but this platform's binary is not attached (yet)
+    let release_url = write_release_metadata(
+        &env.home.path().join("release"),
+        "v999.0.0",
+        &["SHA256SUMS"],
+    );
+    env.create_config("config_version: 1\nupdate_channel: release\nupdate_source_fallback: true\n");
+
+    let bin_dir = env.agent_tools_home.join("bin");
+    fs::create_dir_all(&bin_dir).unwrap();
+    fs::write(bin_dir.join("agent-tools"), "current").unwrap();
+
+    // Neither fails nor falls back to a source build
+    env.cmd()
+        .args(["update"])
+        .env("AGENT_TOOLS_RELEASE_URL", &release_url)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("upload may still be in progress"))
+        .stdout(predicate::str::contains("Falling back").not());
+
+    assert_eq!(
+        fs::read_to_string(bin_dir.join("agent-tools")).unwrap(),
+        "current"
+    );
+    assert!(!env.agent_tools_home.join("backups").exists());
+}
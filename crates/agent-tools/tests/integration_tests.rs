@@ -12,6 +12,7 @@
 //! - skill_update: skill update command
 //! - skill_remove: skill remove command
 //! - skill_diff: skill diff command
+//! - startup: startup command
 //! - status: status command
 //! - cleanup: cleanup command
 //! - skill_new: skill new command
@@ -33,6 +34,7 @@ mod integration {
     mod skill_remove;
     mod skill_update;
     mod skill_validate;
+    mod startup;
     mod status;
     mod sync;
     mod update;